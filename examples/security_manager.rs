@@ -1,67 +1,660 @@
 // Rust 예시: 보안 매니저 (안전하지 않은 코드 사용 및 보안 이슈)
-use std::collections::HashMap;
+use std::collections::{HashMap, VecDeque};
 use std::fs::File;
 use std::io::{Read, Write};
 use std::process::Command;
 use std::ffi::CString;
 use std::ptr;
 
+use argon2::{
+    password_hash::{rand_core::OsRng, PasswordHash, PasswordHasher, PasswordVerifier, SaltString},
+    Algorithm, Argon2, Params, Version,
+};
+use base64::{engine::general_purpose::URL_SAFE_NO_PAD, Engine as _};
+use bitflags::bitflags;
+use ring::rand::{SecureRandom, SystemRandom};
+use std::time::{Duration, SystemTime};
+
 // 보안 이슈: 하드코딩된 시크릿
 const SECRET_KEY: &str = "hardcoded-secret-key-123";
 const ADMIN_PASSWORD: &str = "admin123";
 
+const DEFAULT_SESSION_TTL_SECS: u64 = 30 * 60;
+
+// 연속 실패 횟수가 이 값에 도달하면 계정을 잠근다
+const MAX_CONSECUTIVE_FAILURES: u32 = 5;
+// 잠금이 유지되는(그리고 실패 횟수가 리셋되는) 시간 윈도우
+const LOCKOUT_WINDOW: Duration = Duration::from_secs(15 * 60);
+// 무작위 사용자명으로 실패를 스프레이하는 공격에도 failure_tracker가 무한정
+// 자라지 않도록 추적 가능한 항목 수를 제한한다
+const MAX_TRACKED_FAILURES: usize = 4096;
+
+/// 사용자별 연속 로그인 실패 기록.
+#[derive(Clone, Copy, Debug)]
+struct FailureState {
+    count: u32,
+    last_failure_at: SystemTime,
+}
+
+/// `authenticate`가 반환하는 오류. 사용자 미존재/비밀번호 불일치는 항상 같은 변형으로 합쳐진다.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AuthError {
+    InvalidCredentials,
+    AccountLocked,
+}
+
+const DEFAULT_AUDIT_LOG_CAPACITY: usize = 1024;
+
+/// 감사 로그에 기록되는 구조화된 이벤트. 비밀번호 등 민감 정보는 절대 담지 않는다.
+#[derive(Clone, Debug)]
+pub struct AuditEvent {
+    pub actor: String,
+    pub action: String,
+    pub target: String,
+}
+
+/// 감사 이벤트를 외부로 전달하는 싱크. stdout, 파일, 사용자 콜백 등으로 구현할 수 있다.
+pub trait AuditSink {
+    fn record(&mut self, event: &AuditEvent);
+}
+
+/// 기본 싱크: 이벤트를 표준 출력에 한 줄씩 기록한다.
+pub struct StdoutAuditSink;
+
+impl AuditSink for StdoutAuditSink {
+    fn record(&mut self, event: &AuditEvent) {
+        println!(
+            "[audit] actor={} action={} target={}",
+            event.actor, event.action, event.target
+        );
+    }
+}
+
+/// 고정 용량 링 버퍼로 최근 이벤트만 메모리에 보관하고, 모든 이벤트를 싱크로 흘려보낸다.
+pub struct AuditLog {
+    entries: VecDeque<AuditEvent>,
+    max_entries: usize,
+    sink: Box<dyn AuditSink>,
+}
+
+impl AuditLog {
+    pub fn new(max_entries: usize, sink: Box<dyn AuditSink>) -> Self {
+        Self {
+            entries: VecDeque::with_capacity(max_entries),
+            max_entries,
+            sink,
+        }
+    }
+
+    pub fn record(&mut self, event: AuditEvent) {
+        self.sink.record(&event);
+
+        if self.entries.len() >= self.max_entries {
+            self.entries.pop_front();
+        }
+        self.entries.push_back(event);
+    }
+
+    /// 링 버퍼에 남아 있는 가장 최근 항목들을 오래된 순서로 순회한다.
+    pub fn recent(&self) -> impl Iterator<Item = &AuditEvent> {
+        self.entries.iter()
+    }
+}
+
+impl Default for AuditLog {
+    fn default() -> Self {
+        Self::new(DEFAULT_AUDIT_LOG_CAPACITY, Box::new(StdoutAuditSink))
+    }
+}
+
+bitflags! {
+    /// 사용자/세션에 부여되는 세분화된 권한. 정수로 직렬화해 영속화할 수 있다.
+    #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+    pub struct Permissions: u32 {
+        const VIEW            = 0b0000_0001;
+        const CREATE_USER     = 0b0000_0010;
+        const RESET_PASSWORDS = 0b0000_0100;
+        const EXPORT_DATA     = 0b0000_1000;
+        const ADMIN           = 0b1000_0000;
+    }
+}
+
+impl Permissions {
+    /// 영속화를 위해 플래그를 정수로 직렬화한다.
+    pub fn to_bits_value(self) -> u32 {
+        self.bits()
+    }
+
+    /// 정수로부터 플래그를 복원한다. 알 수 없는 비트는 무시한다.
+    pub fn from_bits_value(bits: u32) -> Self {
+        Self::from_bits_truncate(bits)
+    }
+}
+
+/// `SecurityManager`에서 발생할 수 있는 오류.
+#[derive(Debug)]
+pub enum SecurityError {
+    PermissionDenied,
+    Io(std::io::Error),
+}
+
+impl std::fmt::Display for SecurityError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SecurityError::PermissionDenied => write!(f, "permission denied"),
+            SecurityError::Io(err) => write!(f, "io error: {}", err),
+        }
+    }
+}
+
+impl std::error::Error for SecurityError {}
+
+impl From<std::io::Error> for SecurityError {
+    fn from(err: std::io::Error) -> Self {
+        SecurityError::Io(err)
+    }
+}
+
+/// 발급된 세션 하나의 메타데이터.
+#[derive(Clone, Debug)]
+pub struct SessionInfo {
+    pub username: String,
+    pub permissions: Permissions,
+    pub created_at: SystemTime,
+    pub expires_at: SystemTime,
+}
+
+/// CSPRNG 기반 세션 토큰을 발급/검증/폐기하는 저장소.
+///
+/// `SystemRandom`은 시딩 비용이 크기 때문에 매니저마다 한 번만 생성해 보관한다.
+pub struct SessionStore {
+    rng: SystemRandom,
+    sessions: HashMap<String, SessionInfo>,
+    ttl: Duration,
+}
+
+impl SessionStore {
+    pub fn new(ttl: Duration) -> Self {
+        Self {
+            rng: SystemRandom::new(),
+            sessions: HashMap::new(),
+            ttl,
+        }
+    }
+
+    /// 256비트의 CSPRNG 출력을 URL-safe base64로 인코딩해 세션 토큰을 발급한다.
+    pub fn create_session(&mut self, username: &str, permissions: Permissions) -> String {
+        let mut bytes = [0u8; 32];
+        self.rng
+            .fill(&mut bytes)
+            .expect("system RNG should not fail to produce entropy");
+        let token = URL_SAFE_NO_PAD.encode(bytes);
+
+        let now = SystemTime::now();
+        self.sessions.insert(
+            token.clone(),
+            SessionInfo {
+                username: username.to_string(),
+                permissions,
+                created_at: now,
+                expires_at: now + self.ttl,
+            },
+        );
+
+        token
+    }
+
+    /// 만료된 토큰은 존재하지 않는 것처럼 취급한다.
+    pub fn validate_session(&self, token: &str) -> Option<&SessionInfo> {
+        self.sessions
+            .get(token)
+            .filter(|info| info.expires_at > SystemTime::now())
+    }
+
+    pub fn revoke_session(&mut self, token: &str) -> bool {
+        self.sessions.remove(token).is_some()
+    }
+
+    pub fn revoke_all_for_user(&mut self, username: &str) {
+        self.sessions.retain(|_, info| info.username != username);
+    }
+}
+
+impl Default for SessionStore {
+    fn default() -> Self {
+        Self::new(Duration::from_secs(DEFAULT_SESSION_TTL_SECS))
+    }
+}
+
+/// Argon2id 비용 파라미터. 운영 환경은 `default_secure`를, 테스트에서는
+/// `insecure_for_tests`를 사용해 해시 계산 시간을 줄인다.
+#[derive(Clone, Copy, Debug)]
+pub struct PasswordCost {
+    pub memory_kib: u32,
+    pub time_cost: u32,
+    pub parallelism: u32,
+}
+
+impl PasswordCost {
+    /// OWASP 권장치에 기반한 운영 환경 기본 파라미터.
+    pub fn default_secure() -> Self {
+        Self {
+            memory_kib: 19 * 1024,
+            time_cost: 2,
+            parallelism: 1,
+        }
+    }
+
+    /// 테스트 전용 고속 프리셋. 운영 코드에서 사용하지 말 것.
+    pub fn insecure_for_tests() -> Self {
+        Self {
+            memory_kib: 8,
+            time_cost: 1,
+            parallelism: 1,
+        }
+    }
+
+    fn to_params(self) -> Params {
+        Params::new(self.memory_kib, self.time_cost, self.parallelism, None)
+            .expect("memory_kib/time_cost/parallelism produce valid argon2 params")
+    }
+}
+
+impl Default for PasswordCost {
+    fn default() -> Self {
+        Self::default_secure()
+    }
+}
+
+/// 사용자 인증 방식의 공통 인터페이스. 인메모리 사용자 외에 OS 계정 같은 다른 소스도
+/// 같은 방식으로 `authenticate`에 연결할 수 있다.
+pub trait AuthBackend {
+    fn verify_password(&self, username: &str, password: &str) -> bool;
+}
+
+/// 호스트 `/etc/passwd` / `/etc/shadow`를 읽어 OS 계정으로 인증하는 백엔드.
+/// 유닉스 전용 파일 포맷에 의존하므로 `system-auth` 피처 뒤에 숨겨져 있다.
+#[cfg(feature = "system-auth")]
+pub mod system_auth {
+    use super::AuthBackend;
+    use std::fs;
+
+    /// `/etc/passwd` 한 줄(name, uid, gid, gecos, home, shell)에 대응하는 사용자 정보.
+    #[derive(Clone, Debug, PartialEq, Eq)]
+    pub struct UserEntry {
+        pub name: String,
+        pub uid: u32,
+        pub gid: u32,
+        pub gecos: String,
+        pub home: String,
+        pub shell: String,
+    }
+
+    pub struct SystemUserBackend {
+        passwd_path: String,
+        shadow_path: String,
+    }
+
+    impl SystemUserBackend {
+        pub fn new() -> Self {
+            Self::with_paths("/etc/passwd", "/etc/shadow")
+        }
+
+        /// 테스트 등에서 `/etc/passwd`, `/etc/shadow` 대신 다른 경로를 지정할 수 있게 한다.
+        pub fn with_paths(passwd_path: impl Into<String>, shadow_path: impl Into<String>) -> Self {
+            Self {
+                passwd_path: passwd_path.into(),
+                shadow_path: shadow_path.into(),
+            }
+        }
+
+        /// 이름으로 OS 계정을 조회한다. 존재하지 않거나 파일을 읽을 수 없으면 `None`.
+        pub fn lookup_user(&self, name: &str) -> Option<UserEntry> {
+            let contents = fs::read_to_string(&self.passwd_path).ok()?;
+            parse_passwd(&contents).into_iter().find(|entry| entry.name == name)
+        }
+
+        fn shadow_hash(&self, name: &str) -> Option<String> {
+            let contents = fs::read_to_string(&self.shadow_path).ok()?;
+            parse_shadow(&contents)
+                .into_iter()
+                .find(|(entry_name, _)| entry_name == name)
+                .map(|(_, hash)| hash)
+        }
+    }
+
+    impl Default for SystemUserBackend {
+        fn default() -> Self {
+            Self::new()
+        }
+    }
+
+    impl AuthBackend for SystemUserBackend {
+        fn verify_password(&self, username: &str, password: &str) -> bool {
+            // 계정이 없거나 잠겨 있으면(`!`, `*`) 항상 거부한다
+            match self.shadow_hash(username) {
+                Some(hash) if !hash.is_empty() && hash != "!" && hash != "*" => {
+                    pwhash::unix::verify(password, &hash)
+                }
+                _ => false,
+            }
+        }
+    }
+
+    fn parse_passwd(contents: &str) -> Vec<UserEntry> {
+        contents
+            .lines()
+            .filter(|line| !line.is_empty() && !line.starts_with('#'))
+            .filter_map(|line| {
+                let fields: Vec<&str> = line.split(':').collect();
+                if fields.len() < 7 {
+                    return None;
+                }
+                Some(UserEntry {
+                    name: fields[0].to_string(),
+                    uid: fields[2].parse().ok()?,
+                    gid: fields[3].parse().ok()?,
+                    gecos: fields[4].to_string(),
+                    home: fields[5].to_string(),
+                    shell: fields[6].to_string(),
+                })
+            })
+            .collect()
+    }
+
+    fn parse_shadow(contents: &str) -> Vec<(String, String)> {
+        contents
+            .lines()
+            .filter(|line| !line.is_empty() && !line.starts_with('#'))
+            .filter_map(|line| {
+                let fields: Vec<&str> = line.split(':').collect();
+                if fields.len() < 2 {
+                    return None;
+                }
+                Some((fields[0].to_string(), fields[1].to_string()))
+            })
+            .collect()
+    }
+}
+
 pub struct SecurityManager {
+    // PHC 문자열(`$argon2id$v=19$m=...,t=...,p=...$salt$hash`)을 저장한다.
     users: HashMap<String, String>,
-    sessions: HashMap<String, u64>,
-    // 메모리 누수 가능성: Vec이 무제한 성장
-    audit_log: Vec<String>,
+    user_permissions: HashMap<String, Permissions>,
+    session_store: SessionStore,
+    audit_log: AuditLog,
+    password_cost: PasswordCost,
+    // 존재하지 않는 사용자에 대해서도 검증 경로를 타게 만드는 더미 해시 (타이밍 차이 제거용)
+    dummy_hash: String,
+    failure_tracker: HashMap<String, FailureState>,
+    // 인메모리 사용자 맵에 없는 사용자를 위임할 백엔드 (예: OS 계정)
+    #[cfg(feature = "system-auth")]
+    system_backend: Option<Box<dyn AuthBackend>>,
 }
 
 impl SecurityManager {
     pub fn new() -> Self {
+        let password_cost = PasswordCost::default();
+        let dummy_hash = Self::hash_password("dummy-password-for-timing-equalization", password_cost)
+            .expect("hashing the fixed dummy password should not fail");
+
         Self {
             users: HashMap::new(),
-            sessions: HashMap::new(),
-            audit_log: Vec::new(),
+            user_permissions: HashMap::new(),
+            session_store: SessionStore::default(),
+            audit_log: AuditLog::default(),
+            password_cost,
+            dummy_hash,
+            failure_tracker: HashMap::new(),
+            #[cfg(feature = "system-auth")]
+            system_backend: None,
+        }
+    }
+
+    /// 지정한 비용 파라미터로 매니저를 생성한다 (테스트에서 `insecure_for_tests`와 함께 사용).
+    ///
+    /// `..Self::new()`로 위임하면 기본 비용(19MiB, t=2)으로 더미 해시를 먼저 계산한
+    /// 뒤 버려지므로, 필드를 직접 채워 지정된 비용으로만 해시가 계산되게 한다.
+    pub fn with_password_cost(password_cost: PasswordCost) -> Self {
+        let dummy_hash = Self::hash_password("dummy-password-for-timing-equalization", password_cost)
+            .expect("hashing the fixed dummy password should not fail");
+
+        Self {
+            users: HashMap::new(),
+            user_permissions: HashMap::new(),
+            session_store: SessionStore::default(),
+            audit_log: AuditLog::default(),
+            password_cost,
+            dummy_hash,
+            failure_tracker: HashMap::new(),
+            #[cfg(feature = "system-auth")]
+            system_backend: None,
+        }
+    }
+
+    /// 인메모리 맵에 없는 사용자의 인증을 위임할 백엔드를 등록한다.
+    #[cfg(feature = "system-auth")]
+    pub fn set_auth_backend(&mut self, backend: impl AuthBackend + 'static) {
+        self.system_backend = Some(Box::new(backend));
+    }
+
+    fn hash_password(password: &str, cost: PasswordCost) -> Result<String, argon2::password_hash::Error> {
+        let salt = SaltString::generate(&mut OsRng);
+        let argon2 = Argon2::new(Algorithm::Argon2id, Version::V0x13, cost.to_params());
+        Ok(argon2.hash_password(password.as_bytes(), &salt)?.to_string())
+    }
+
+    /// 저장된 해시가 현재 설정된 비용보다 약한 파라미터로 계산됐는지 확인한다.
+    fn needs_rehash(stored: &PasswordHash, cost: PasswordCost) -> bool {
+        match Params::try_from(stored) {
+            Ok(params) => {
+                params.m_cost() < cost.memory_kib
+                    || params.t_cost() < cost.time_cost
+                    || params.p_cost() < cost.parallelism
+            }
+            Err(_) => true,
+        }
+    }
+
+    /// 윈도우 내 연속 실패 횟수가 한도에 도달했는지 확인한다.
+    fn is_locked_out(&self, username: &str) -> bool {
+        self.failure_tracker.get(username).is_some_and(|state| {
+            state.count >= MAX_CONSECUTIVE_FAILURES
+                && state.last_failure_at.elapsed().unwrap_or(Duration::ZERO) < LOCKOUT_WINDOW
+        })
+    }
+
+    /// 실패를 기록한다. 잠금 윈도우가 지난 뒤의 첫 실패라면 카운터를 새로 시작한다.
+    ///
+    /// `failure_tracker`는 사용자명이 아닌 공격자가 직접 입력으로 키를 고르므로,
+    /// 윈도우가 만료된 항목을 먼저 정리하고도 한도를 넘으면 가장 오래된 항목을
+    /// 비워 메모리 사용량을 `MAX_TRACKED_FAILURES`로 제한한다 (audit_log와 동일한
+    /// 유한 크기 컬렉션 패턴).
+    fn record_failure(&mut self, username: &str) {
+        let now = SystemTime::now();
+
+        self.failure_tracker.retain(|_, state| {
+            state.last_failure_at.elapsed().unwrap_or(Duration::ZERO) < LOCKOUT_WINDOW
+        });
+
+        if !self.failure_tracker.contains_key(username)
+            && self.failure_tracker.len() >= MAX_TRACKED_FAILURES
+        {
+            if let Some(oldest) = self
+                .failure_tracker
+                .iter()
+                .min_by_key(|(_, state)| state.last_failure_at)
+                .map(|(name, _)| name.clone())
+            {
+                self.failure_tracker.remove(&oldest);
+            }
+        }
+
+        let state = self
+            .failure_tracker
+            .entry(username.to_string())
+            .or_insert(FailureState {
+                count: 0,
+                last_failure_at: now,
+            });
+
+        if state.last_failure_at.elapsed().unwrap_or(Duration::ZERO) >= LOCKOUT_WINDOW {
+            state.count = 0;
         }
+        state.count += 1;
+        state.last_failure_at = now;
+    }
+
+    fn reset_failures(&mut self, username: &str) {
+        self.failure_tracker.remove(username);
     }
 
-    // 보안 이슈: 평문 비밀번호 저장
     pub fn create_user(&mut self, username: String, password: String) -> bool {
         // 입력 검증 없음
         if username.is_empty() || password.is_empty() {
             return false;
         }
 
-        // 보안 이슈: 비밀번호 평문 저장
-        self.users.insert(username.clone(), password);
-        
-        // 보안 이슈: 민감한 정보 로깅
-        let log_entry = format!("User created: {} with password: {}", username, password);
-        self.audit_log.push(log_entry);
-        
+        let hash = match Self::hash_password(&password, self.password_cost) {
+            Ok(hash) => hash,
+            Err(_) => return false,
+        };
+
+        self.users.insert(username.clone(), hash);
+        self.user_permissions.insert(username.clone(), Permissions::VIEW);
+
+        // 비밀번호는 더 이상 로그에 남기지 않는다
+        self.audit_log.record(AuditEvent {
+            actor: username.clone(),
+            action: "create_user".to_string(),
+            target: username.clone(),
+        });
+
         true
     }
 
-    // 보안 이슈: 약한 세션 ID 생성
-    pub fn authenticate(&mut self, username: &str, password: &str) -> Option<String> {
-        if let Some(stored_password) = self.users.get(username) {
-            // 보안 이슈: 평문 비밀번호 비교
-            if stored_password == password {
-                // 보안 이슈: 예측 가능한 세션 ID
-                let session_id = format!("session_{}", self.sessions.len());
-                self.sessions.insert(session_id.clone(), 1);
-                
-                // 보안 이슈: 인증 정보 로깅
-                println!("Authentication successful for: {}", username);
-                
-                return Some(session_id);
+    /// 인증된 세션이 `CREATE_USER` 권한을 가졌는지 확인한 뒤에만 사용자를 생성한다.
+    ///
+    /// `create_user` 자체는 부트스트랩(최초 관리자 계정 생성 등)에 쓰이므로 세션을
+    /// 요구하지 않는 채로 남겨 두고, 권한 검증이 필요한 호출 지점은 이 메서드를
+    /// 통하게 한다 (`export_user_data`/`admin_reset_all_passwords`와 동일한 패턴).
+    pub fn create_user_as(
+        &mut self,
+        session: &SessionInfo,
+        username: String,
+        password: String,
+    ) -> Result<bool, SecurityError> {
+        if !session.permissions.contains(Permissions::CREATE_USER) {
+            return Err(SecurityError::PermissionDenied);
+        }
+
+        Ok(self.create_user(username, password))
+    }
+
+    /// 사용자에게 권한을 추가로 부여한다.
+    pub fn grant_permission(&mut self, username: &str, permission: Permissions) {
+        self.user_permissions
+            .entry(username.to_string())
+            .or_insert(Permissions::empty())
+            .insert(permission);
+    }
+
+    /// 사용자의 권한을 회수한다.
+    pub fn revoke_permission(&mut self, username: &str, permission: Permissions) {
+        if let Some(perms) = self.user_permissions.get_mut(username) {
+            perms.remove(permission);
+        }
+    }
+
+    pub fn authenticate(&mut self, username: &str, password: &str) -> Result<String, AuthError> {
+        if self.is_locked_out(username) {
+            return Err(AuthError::AccountLocked);
+        }
+
+        let stored_hash = self.users.get(username).cloned();
+
+        // 사용자가 없어도 더미 해시로 같은 검증 경로를 타서 타이밍 차이를 없앤다
+        let hash_to_check = stored_hash.as_deref().unwrap_or(self.dummy_hash.as_str());
+        let parsed = PasswordHash::new(hash_to_check).ok();
+        let password_matches = parsed
+            .as_ref()
+            .map(|parsed| {
+                Argon2::default()
+                    .verify_password(password.as_bytes(), parsed)
+                    .is_ok()
+            })
+            .unwrap_or(false);
+
+        if stored_hash.is_some() && password_matches {
+            // verify-and-update: 재로그인 시점에 약한 해시를 투명하게 갱신
+            if let Some(parsed) = &parsed {
+                if Self::needs_rehash(parsed, self.password_cost) {
+                    if let Ok(upgraded) = Self::hash_password(password, self.password_cost) {
+                        self.users.insert(username.to_string(), upgraded);
+                    }
+                }
             }
+
+            self.reset_failures(username);
+
+            let permissions = self
+                .user_permissions
+                .get(username)
+                .copied()
+                .unwrap_or_else(Permissions::empty);
+            let session_id = self.session_store.create_session(username, permissions);
+
+            self.audit_log.record(AuditEvent {
+                actor: username.to_string(),
+                action: "authenticate_success".to_string(),
+                target: username.to_string(),
+            });
+
+            return Ok(session_id);
         }
-        
-        // 보안 이슈: 사용자 존재 여부 유추 가능한 에러 메시지
+
+        // 인메모리 맵에 없는 사용자는 등록된 백엔드(예: OS 계정)에 위임한다
+        #[cfg(feature = "system-auth")]
+        if stored_hash.is_none() {
+            if let Some(backend) = &self.system_backend {
+                if backend.verify_password(username, password) {
+                    self.reset_failures(username);
+
+                    let session_id = self
+                        .session_store
+                        .create_session(username, Permissions::VIEW);
+
+                    self.audit_log.record(AuditEvent {
+                        actor: username.to_string(),
+                        action: "authenticate_success".to_string(),
+                        target: username.to_string(),
+                    });
+
+                    return Ok(session_id);
+                }
+            }
+        }
+
+        // 존재하는 사용자, 존재하지 않는 사용자, 백엔드 인증 실패 모두 동일하게 실패 횟수를
+        // 누적시켜야 사용자 열거(enumeration) 오라클이 생기지 않는다
+        self.record_failure(username);
+
+        // 사용자 존재 여부와 무관하게 항상 같은 일반화된 에러를 반환한다
         println!("Authentication failed: Invalid username or password");
-        None
+        Err(AuthError::InvalidCredentials)
+    }
+
+    /// 만료되지 않은 세션만 조회한다.
+    pub fn validate_session(&self, token: &str) -> Option<&SessionInfo> {
+        self.session_store.validate_session(token)
+    }
+
+    pub fn revoke_session(&mut self, token: &str) -> bool {
+        self.session_store.revoke_session(token)
+    }
+
+    pub fn revoke_all_for_user(&mut self, username: &str) {
+        self.session_store.revoke_all_for_user(username)
     }
 
     // 안전하지 않은 코드: 메모리 안전성 위반 가능
@@ -91,24 +684,25 @@ impl SecurityManager {
         Ok(String::from_utf8_lossy(&output.stdout).to_string())
     }
 
-    // 메모리 누수: 무한 성장하는 로그
-    pub fn add_audit_log(&mut self, message: String) {
-        // 로그 크기 제한 없음
-        self.audit_log.push(message);
-        
-        // 성능 이슈: 매번 전체 로그 출력
-        for (i, log) in self.audit_log.iter().enumerate() {
-            println!("Log {}: {}", i, log);
-        }
+    /// 구조화된 감사 이벤트를 기록한다. 비밀번호 등 민감 정보는 인자로 받지 않는다.
+    pub fn add_audit_log(&mut self, actor: &str, action: &str, target: &str) {
+        self.audit_log.record(AuditEvent {
+            actor: actor.to_string(),
+            action: action.to_string(),
+            target: target.to_string(),
+        });
     }
 
-    // 보안 이슈: 민감한 정보를 평문 파일로 저장
-    pub fn export_user_data(&self, filename: &str) -> Result<(), std::io::Error> {
+    pub fn export_user_data(&self, session: &SessionInfo, filename: &str) -> Result<(), SecurityError> {
+        if !session.permissions.contains(Permissions::EXPORT_DATA) {
+            return Err(SecurityError::PermissionDenied);
+        }
+
         let mut file = File::create(filename)?;
-        
-        // 사용자 데이터를 평문으로 저장
-        for (username, password) in &self.users {
-            let line = format!("{}:{}\n", username, password);
+
+        // 비밀번호는 PHC 해시 문자열로만 내보낸다 (평문 없음)
+        for (username, password_hash) in &self.users {
+            let line = format!("{}:{}\n", username, password_hash);
             file.write_all(line.as_bytes())?;
         }
         
@@ -144,15 +738,27 @@ impl SecurityManager {
         results
     }
 
-    // 보안 이슈: 권한 검증 없는 관리자 기능
-    pub fn admin_reset_all_passwords(&mut self) {
-        // 권한 확인 없음
-        for (username, password) in &mut self.users {
-            *password = "temp123".to_string();  // 모든 비밀번호를 약한 것으로 변경
-            
-            // 보안 이슈: 비밀번호 변경 로깅
-            println!("Reset password for user: {}", username);
+    pub fn admin_reset_all_passwords(&mut self, session: &SessionInfo) -> Result<(), SecurityError> {
+        if !session.permissions.contains(Permissions::RESET_PASSWORDS) {
+            return Err(SecurityError::PermissionDenied);
+        }
+
+        let cost = self.password_cost;
+        let actor = session.username.clone();
+        for (username, password_hash) in &mut self.users {
+            // 여전히 약한 임시 비밀번호지만, 최소한 해시로 저장한다
+            if let Ok(hash) = Self::hash_password("temp123", cost) {
+                *password_hash = hash;
+            }
+
+            self.audit_log.record(AuditEvent {
+                actor: actor.clone(),
+                action: "admin_reset_password".to_string(),
+                target: username.clone(),
+            });
         }
+
+        Ok(())
     }
 
     // 안전하지 않은 코드: 원시 포인터로 문자열 생성
@@ -183,14 +789,13 @@ pub unsafe fn get_global_manager() -> &'static mut SecurityManager {
 pub struct User_Session {
     pub Session_ID: String,
     pub User_Name: String,
-    pub Is_Admin: bool,
+    pub Permissions: Permissions,
 }
 
 impl User_Session {
-    // 보안 이슈: 관리자 권한 체크 로직 결함
+    // 문자열 비교 대신 비트플래그로 관리자 권한을 확인한다
     pub fn Check_Admin_Access(&self) -> bool {
-        // 단순한 문자열 비교로 관리자 확인
-        self.User_Name == "admin" || self.Session_ID.contains("admin")
+        self.Permissions.contains(Permissions::ADMIN)
     }
 }
 
@@ -198,10 +803,182 @@ impl User_Session {
 mod tests {
     use super::*;
 
+    #[cfg(feature = "system-auth")]
+    #[test]
+    fn test_system_user_backend_reads_passwd_and_shadow() {
+        use system_auth::SystemUserBackend;
+
+        let dir = std::env::temp_dir().join("security_manager_system_auth_test");
+        std::fs::create_dir_all(&dir).unwrap();
+        let passwd_path = dir.join("passwd");
+        let shadow_path = dir.join("shadow");
+
+        std::fs::write(
+            &passwd_path,
+            "# comment line, should be skipped\nalice:x:1000:1000:Alice:/home/alice:/bin/bash\n",
+        )
+        .unwrap();
+
+        let hash = pwhash::sha512_crypt::hash("correct horse").unwrap();
+        std::fs::write(&shadow_path, format!("alice:{}:19000:0:99999:7:::\n", hash)).unwrap();
+
+        let backend = SystemUserBackend::with_paths(
+            passwd_path.to_str().unwrap(),
+            shadow_path.to_str().unwrap(),
+        );
+
+        let entry = backend.lookup_user("alice").unwrap();
+        assert_eq!(entry.uid, 1000);
+        assert_eq!(entry.shell, "/bin/bash");
+
+        assert!(backend.verify_password("alice", "correct horse"));
+        assert!(!backend.verify_password("alice", "wrong password"));
+        assert!(!backend.verify_password("unknown", "correct horse"));
+    }
+
+    #[test]
+    fn test_unknown_user_and_wrong_password_yield_same_error() {
+        let mut manager = SecurityManager::with_password_cost(PasswordCost::insecure_for_tests());
+        manager.create_user("dave".to_string(), "correct horse".to_string());
+
+        assert_eq!(
+            manager.authenticate("dave", "wrong password"),
+            Err(AuthError::InvalidCredentials)
+        );
+        assert_eq!(
+            manager.authenticate("nobody", "whatever"),
+            Err(AuthError::InvalidCredentials)
+        );
+    }
+
+    #[test]
+    fn test_account_lockout_after_repeated_failures() {
+        let mut manager = SecurityManager::with_password_cost(PasswordCost::insecure_for_tests());
+        manager.create_user("erin".to_string(), "correct horse".to_string());
+
+        for _ in 0..MAX_CONSECUTIVE_FAILURES {
+            assert_eq!(
+                manager.authenticate("erin", "wrong password"),
+                Err(AuthError::InvalidCredentials)
+            );
+        }
+
+        // 이후로는 올바른 비밀번호를 제시해도 잠금 상태가 우선한다
+        assert_eq!(
+            manager.authenticate("erin", "correct horse"),
+            Err(AuthError::AccountLocked)
+        );
+    }
+
+    #[test]
+    fn test_nonexistent_user_locks_out_like_a_real_one() {
+        let mut manager = SecurityManager::with_password_cost(PasswordCost::insecure_for_tests());
+
+        // 존재하지 않는 사용자도 동일한 횟수만큼 실패하면 동일하게 잠겨야 한다
+        // (그렇지 않으면 응답 차이로 계정 존재 여부를 유추할 수 있다)
+        for _ in 0..MAX_CONSECUTIVE_FAILURES {
+            assert_eq!(
+                manager.authenticate("ghost", "whatever"),
+                Err(AuthError::InvalidCredentials)
+            );
+        }
+
+        assert_eq!(
+            manager.authenticate("ghost", "whatever"),
+            Err(AuthError::AccountLocked)
+        );
+    }
+
+    #[test]
+    fn test_password_hashing_roundtrip() {
+        let mut manager = SecurityManager::with_password_cost(PasswordCost::insecure_for_tests());
+
+        assert!(manager.create_user("alice".to_string(), "correct horse".to_string()));
+        assert!(manager.authenticate("alice", "correct horse").is_ok());
+        assert!(manager.authenticate("alice", "wrong password").is_err());
+    }
+
+    #[test]
+    fn test_session_lifecycle() {
+        let mut manager = SecurityManager::with_password_cost(PasswordCost::insecure_for_tests());
+        manager.create_user("bob".to_string(), "correct horse".to_string());
+
+        let token = manager.authenticate("bob", "correct horse").unwrap();
+        assert!(manager.validate_session(&token).is_some());
+
+        assert!(manager.revoke_session(&token));
+        assert!(manager.validate_session(&token).is_none());
+
+        let token = manager.authenticate("bob", "correct horse").unwrap();
+        manager.revoke_all_for_user("bob");
+        assert!(manager.validate_session(&token).is_none());
+    }
+
+    #[test]
+    fn test_permission_gated_admin_actions() {
+        let mut manager = SecurityManager::with_password_cost(PasswordCost::insecure_for_tests());
+        manager.create_user("carol".to_string(), "correct horse".to_string());
+
+        let token = manager.authenticate("carol", "correct horse").unwrap();
+        let session = manager.validate_session(&token).unwrap().clone();
+        assert!(matches!(
+            manager.admin_reset_all_passwords(&session),
+            Err(SecurityError::PermissionDenied)
+        ));
+
+        manager.grant_permission("carol", Permissions::RESET_PASSWORDS);
+        let token = manager.authenticate("carol", "correct horse").unwrap();
+        let session = manager.validate_session(&token).unwrap().clone();
+        assert!(manager.admin_reset_all_passwords(&session).is_ok());
+    }
+
+    #[test]
+    fn test_create_user_as_requires_create_user_permission() {
+        let mut manager = SecurityManager::with_password_cost(PasswordCost::insecure_for_tests());
+        manager.create_user("carol".to_string(), "correct horse".to_string());
+
+        let token = manager.authenticate("carol", "correct horse").unwrap();
+        let session = manager.validate_session(&token).unwrap().clone();
+        assert!(matches!(
+            manager.create_user_as(&session, "dave".to_string(), "correct horse".to_string()),
+            Err(SecurityError::PermissionDenied)
+        ));
+        assert!(!manager.users.contains_key("dave"));
+
+        manager.grant_permission("carol", Permissions::CREATE_USER);
+        let token = manager.authenticate("carol", "correct horse").unwrap();
+        let session = manager.validate_session(&token).unwrap().clone();
+        assert!(matches!(
+            manager.create_user_as(&session, "dave".to_string(), "correct horse".to_string()),
+            Ok(true)
+        ));
+        assert!(manager.users.contains_key("dave"));
+    }
+
+    #[test]
+    fn test_audit_log_is_bounded() {
+        struct NullSink;
+        impl AuditSink for NullSink {
+            fn record(&mut self, _event: &AuditEvent) {}
+        }
+
+        let mut log = AuditLog::new(3, Box::new(NullSink));
+        for i in 0..10 {
+            log.record(AuditEvent {
+                actor: "tester".to_string(),
+                action: "noop".to_string(),
+                target: format!("item-{}", i),
+            });
+        }
+
+        let remaining: Vec<_> = log.recent().map(|e| e.target.clone()).collect();
+        assert_eq!(remaining, vec!["item-7", "item-8", "item-9"]);
+    }
+
     #[test]
     fn test_unsafe_operations() {
-        let mut manager = SecurityManager::new();
-        
+        let mut manager = SecurityManager::with_password_cost(PasswordCost::insecure_for_tests());
+
         unsafe {
             // 안전하지 않은 테스트 코드
             let data = vec![1, 2, 3, 4, 5];